@@ -0,0 +1,40 @@
+use chrono::Utc;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Records one row in the `audit` table for a mutating request. Called from
+/// the create/update/delete handlers after the underlying write succeeds, so
+/// a failed mutation never produces an audit entry.
+///
+/// Audit logging is supplementary: the underlying mutation has already
+/// happened (and, for orders, already been committed) by the time this is
+/// called, so a failure here must not turn a successful request into a 500.
+/// Failures are logged via `tracing::error!` and otherwise swallowed.
+pub async fn record(
+    pool: &PgPool,
+    actor: Option<Uuid>,
+    action: &str,
+    target_resource: &str,
+    target_id: Uuid,
+    body: &impl Serialize,
+) {
+    let body = serde_json::to_value(body).unwrap_or(serde_json::Value::Null);
+
+    let result = sqlx::query(
+        "INSERT INTO audit (actor, action, target_resource, target_id, body, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(actor)
+    .bind(action)
+    .bind(target_resource)
+    .bind(target_id)
+    .bind(body)
+    .bind(Utc::now().naive_utc())
+    .execute(pool)
+    .await;
+
+    if let Err(err) = result {
+        tracing::error!(error = %err, action, target_resource, %target_id, "failed to record audit log entry");
+    }
+}
@@ -0,0 +1,26 @@
+// Environment-backed configuration for the service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub jwt_secret: String,
+    pub jwt_maxage: i64,
+}
+
+impl Config {
+    /// Reads configuration from the process environment, panicking with a
+    /// descriptive message if a required variable is missing.
+    pub fn init() -> Self {
+        let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+        let jwt_maxage = std::env::var("JWT_MAXAGE")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<i64>()
+            .expect("JWT_MAXAGE must be an integer number of minutes");
+
+        Self {
+            database_url,
+            jwt_secret,
+            jwt_maxage,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use axum::{http::StatusCode, response::IntoResponse, Json};
+use serde_json::json;
+
+/// Unified error type returned by every handler. Each variant maps to a
+/// specific status code and a JSON body of the form `{"error": "..."}`,
+/// replacing the previous pattern of `eprintln!` + a bare 500.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("database error: {0}")]
+    Database(sqlx::Error),
+
+    #[error("resource not found")]
+    NotFound,
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("unauthorized")]
+    Unauthorized,
+}
+
+// `sqlx::Error::RowNotFound` is surfaced as `Error::NotFound` so that a
+// missing row turns into a 404 everywhere a query uses `?`, without every
+// handler having to special-case it.
+impl From<sqlx::Error> for Error {
+    fn from(err: sqlx::Error) -> Self {
+        match err {
+            sqlx::Error::RowNotFound => Error::NotFound,
+            other => Error::Database(other),
+        }
+    }
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> axum::response::Response {
+        let status = match &self {
+            Error::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NotFound => StatusCode::NOT_FOUND,
+            Error::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Error::Unauthorized => StatusCode::UNAUTHORIZED,
+        };
+
+        if let Error::Database(err) = &self {
+            tracing::error!(error = %err, "database error");
+        }
+
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
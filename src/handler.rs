@@ -0,0 +1,531 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::audit;
+use crate::error::Error;
+use crate::jwt_auth::AccessClaims;
+use crate::model::{
+    AppState, Audit, Customer, CustomerCredentials, ListParams, LoginInput, LoginResponse,
+    NewOrder, Order, OrderListParams, Paginated, Product, UpdateCustomer, UpdateOrder,
+    UpdateProduct,
+};
+use crate::pagination::{normalize_limit, normalize_offset, resolve_sort};
+
+// GET `/products`: Fetch one page of products
+pub async fn get_products(
+    State(state): State<AppState>,
+    Query(params): Query<ListParams>,
+) -> Result<Json<Paginated<Product>>, Error> {
+    let limit = normalize_limit(params.limit);
+    let offset = normalize_offset(params.offset);
+    let (column, descending) = resolve_sort(params.sort.as_deref(), &["name", "stock"], "name");
+    let direction = if descending { "DESC" } else { "ASC" };
+
+    let products = sqlx::query_as::<_, Product>(&format!(
+        "SELECT id, name, stock FROM product ORDER BY {column} {direction} LIMIT $1 OFFSET $2"
+    ))
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&*state.pool)
+    .await?;
+
+    let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM product")
+        .fetch_one(&*state.pool)
+        .await?;
+
+    Ok(Json(Paginated {
+        data: products,
+        total,
+        limit,
+        offset,
+    }))
+}
+
+// GET `/products/:id`: Fetch a single product
+pub async fn get_product(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Product>, Error> {
+    let product =
+        sqlx::query_as::<_, Product>("SELECT id, name, stock FROM product WHERE id = $1")
+            .bind(id)
+            .fetch_one(&*state.pool)
+            .await?;
+    Ok(Json(product))
+}
+
+// PUT `/products/:id`: Update a product's name (requires a valid access token)
+pub async fn update_product(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateProduct>,
+) -> Result<Json<Product>, Error> {
+    let product = sqlx::query_as::<_, Product>(
+        "UPDATE product SET name = $1 WHERE id = $2 RETURNING id, name, stock",
+    )
+    .bind(payload.name.clone())
+    .bind(id)
+    .fetch_one(&*state.pool)
+    .await?;
+
+    audit::record(&state.pool, Some(claims.sub), "update", "product", id, &payload).await;
+
+    Ok(Json(product))
+}
+
+// DELETE `/products/:id`: Remove a product (requires a valid access token)
+pub async fn delete_product(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, Error> {
+    let result = sqlx::query("DELETE FROM product WHERE id = $1")
+        .bind(id)
+        .execute(&*state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(Error::NotFound);
+    }
+
+    audit::record(&state.pool, Some(claims.sub), "delete", "product", id, &serde_json::json!({})).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// GET `/customers`: Fetch one page of customers
+pub async fn get_customers(
+    State(state): State<AppState>,
+    Query(params): Query<ListParams>,
+) -> Result<Json<Paginated<Customer>>, Error> {
+    let limit = normalize_limit(params.limit);
+    let offset = normalize_offset(params.offset);
+    let (column, descending) = resolve_sort(params.sort.as_deref(), &["name"], "name");
+    let direction = if descending { "DESC" } else { "ASC" };
+
+    let customers = sqlx::query_as::<_, Customer>(&format!(
+        "SELECT id, name FROM customer ORDER BY {column} {direction} LIMIT $1 OFFSET $2"
+    ))
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&*state.pool)
+    .await?;
+
+    let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM customer")
+        .fetch_one(&*state.pool)
+        .await?;
+
+    Ok(Json(Paginated {
+        data: customers,
+        total,
+        limit,
+        offset,
+    }))
+}
+
+// GET `/customers/:id`: Fetch a single customer
+pub async fn get_customer(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Customer>, Error> {
+    let customer = sqlx::query_as::<_, Customer>("SELECT id, name FROM customer WHERE id = $1")
+        .bind(id)
+        .fetch_one(&*state.pool)
+        .await?;
+    Ok(Json(customer))
+}
+
+// PUT `/customers/:id`: Update a customer's name (requires a valid access
+// token belonging to that customer)
+pub async fn update_customer(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateCustomer>,
+) -> Result<Json<Customer>, Error> {
+    if id != claims.sub {
+        return Err(Error::NotFound);
+    }
+
+    let customer = sqlx::query_as::<_, Customer>(
+        "UPDATE customer SET name = $1 WHERE id = $2 RETURNING id, name",
+    )
+    .bind(payload.name.clone())
+    .bind(id)
+    .fetch_one(&*state.pool)
+    .await?;
+
+    audit::record(&state.pool, Some(claims.sub), "update", "customer", id, &payload).await;
+
+    Ok(Json(customer))
+}
+
+// DELETE `/customers/:id`: Remove a customer (requires a valid access token
+// belonging to that customer)
+pub async fn delete_customer(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, Error> {
+    if id != claims.sub {
+        return Err(Error::NotFound);
+    }
+
+    let result = sqlx::query("DELETE FROM customer WHERE id = $1")
+        .bind(id)
+        .execute(&*state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(Error::NotFound);
+    }
+
+    audit::record(&state.pool, Some(claims.sub), "delete", "customer", id, &serde_json::json!({})).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// GET `/orders`: Fetch one page of the orders belonging to the caller's own
+// customer (requires a valid access token)
+pub async fn get_orders(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Query(params): Query<OrderListParams>,
+) -> Result<Json<Paginated<Order>>, Error> {
+    let limit = normalize_limit(params.limit);
+    let offset = normalize_offset(params.offset);
+    let (column, descending) =
+        resolve_sort(params.sort.as_deref(), &["order_date", "quantity"], "order_date");
+    let direction = if descending { "DESC" } else { "ASC" };
+
+    let mut data_query: QueryBuilder<Postgres> = QueryBuilder::new(
+        "SELECT id, customer_id, product_id, quantity, order_date FROM \"order\"",
+    );
+    let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new("SELECT COUNT(*) FROM \"order\"");
+
+    for query in [&mut data_query, &mut count_query] {
+        query.push(" WHERE customer_id = ").push_bind(claims.sub);
+        if let Some(from) = params.from {
+            query.push(" AND order_date >= ").push_bind(from);
+        }
+        if let Some(to) = params.to {
+            query.push(" AND order_date <= ").push_bind(to);
+        }
+    }
+
+    data_query.push(format!(" ORDER BY {column} {direction} LIMIT "));
+    data_query.push_bind(limit);
+    data_query.push(" OFFSET ");
+    data_query.push_bind(offset);
+
+    let orders = data_query
+        .build_query_as::<Order>()
+        .fetch_all(&*state.pool)
+        .await?;
+    let total = count_query
+        .build_query_scalar::<i64>()
+        .fetch_one(&*state.pool)
+        .await?;
+
+    Ok(Json(Paginated {
+        data: orders,
+        total,
+        limit,
+        offset,
+    }))
+}
+
+// GET `/orders/:id`: Fetch a single order (requires a valid access token
+// belonging to the order's own customer)
+pub async fn get_order(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Order>, Error> {
+    let order = sqlx::query_as::<_, Order>(
+        "SELECT id, customer_id, product_id, quantity, order_date FROM \"order\"
+        WHERE id = $1 AND customer_id = $2",
+    )
+    .bind(id)
+    .bind(claims.sub)
+    .fetch_one(&*state.pool)
+    .await?;
+    Ok(Json(order))
+}
+
+// POST `/orders`: Add a new order (requires a valid access token)
+//
+// Runs inside a transaction: quantity must be positive, the customer and
+// product must exist, and stock is decremented atomically with the insert.
+// Any failure aborts the transaction, so a rejected order never leaves a
+// partial write behind.
+pub async fn add_order(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Json(payload): Json<NewOrder>,
+) -> Result<Json<Order>, Error> {
+    if payload.quantity <= 0 {
+        return Err(Error::Validation(
+            "quantity must be greater than zero".to_string(),
+        ));
+    }
+
+    let mut tx = state.pool.begin().await?;
+
+    let customer_exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM customer WHERE id = $1)")
+            .bind(claims.sub)
+            .fetch_one(&mut *tx)
+            .await?;
+    if !customer_exists {
+        return Err(Error::Validation("customer does not exist".to_string()));
+    }
+
+    let product_exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM product WHERE id = $1)")
+            .bind(payload.product_id)
+            .fetch_one(&mut *tx)
+            .await?;
+    if !product_exists {
+        return Err(Error::Validation("product does not exist".to_string()));
+    }
+
+    let remaining_stock = sqlx::query_scalar::<_, i32>(
+        "UPDATE product SET stock = stock - $1 WHERE id = $2 AND stock >= $1 RETURNING stock",
+    )
+    .bind(payload.quantity)
+    .bind(payload.product_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if remaining_stock.is_none() {
+        return Err(Error::Validation("insufficient stock".to_string()));
+    }
+
+    let new_order = sqlx::query_as::<_, Order>(
+        "INSERT INTO \"order\" (customer_id, product_id, quantity, order_date)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, customer_id, product_id, quantity, order_date",
+    )
+    .bind(claims.sub)
+    .bind(payload.product_id)
+    .bind(payload.quantity)
+    .bind(Utc::now().naive_utc())
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        &state.pool,
+        Some(claims.sub),
+        "create",
+        "order",
+        new_order.id,
+        &payload,
+    )
+    .await;
+
+    Ok(Json(new_order))
+}
+
+// PUT `/orders/:id`: Update an order (requires a valid access token
+// belonging to the order's own customer)
+//
+// Runs inside a transaction alongside the same stock bookkeeping `add_order`
+// performs: the old product's stock is restored, then the new product's
+// stock is decremented for the new quantity. Changing only the quantity on
+// the same product is just a smaller/larger decrement of that one product.
+pub async fn update_order(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateOrder>,
+) -> Result<Json<Order>, Error> {
+    if payload.quantity <= 0 {
+        return Err(Error::Validation(
+            "quantity must be greater than zero".to_string(),
+        ));
+    }
+
+    let mut tx = state.pool.begin().await?;
+
+    let existing = sqlx::query_as::<_, Order>(
+        "SELECT id, customer_id, product_id, quantity, order_date FROM \"order\"
+        WHERE id = $1 AND customer_id = $2 FOR UPDATE",
+    )
+    .bind(id)
+    .bind(claims.sub)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if payload.product_id != existing.product_id {
+        let product_exists =
+            sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM product WHERE id = $1)")
+                .bind(payload.product_id)
+                .fetch_one(&mut *tx)
+                .await?;
+        if !product_exists {
+            return Err(Error::Validation("product does not exist".to_string()));
+        }
+
+        sqlx::query("UPDATE product SET stock = stock + $1 WHERE id = $2")
+            .bind(existing.quantity)
+            .bind(existing.product_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let remaining_stock = sqlx::query_scalar::<_, i32>(
+            "UPDATE product SET stock = stock - $1 WHERE id = $2 AND stock >= $1 RETURNING stock",
+        )
+        .bind(payload.quantity)
+        .bind(payload.product_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if remaining_stock.is_none() {
+            return Err(Error::Validation("insufficient stock".to_string()));
+        }
+    } else {
+        let delta = payload.quantity - existing.quantity;
+        if delta > 0 {
+            let remaining_stock = sqlx::query_scalar::<_, i32>(
+                "UPDATE product SET stock = stock - $1 WHERE id = $2 AND stock >= $1 RETURNING stock",
+            )
+            .bind(delta)
+            .bind(existing.product_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+            if remaining_stock.is_none() {
+                return Err(Error::Validation("insufficient stock".to_string()));
+            }
+        } else if delta < 0 {
+            sqlx::query("UPDATE product SET stock = stock + $1 WHERE id = $2")
+                .bind(-delta)
+                .bind(existing.product_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+    }
+
+    let order = sqlx::query_as::<_, Order>(
+        "UPDATE \"order\" SET product_id = $1, quantity = $2
+        WHERE id = $3 AND customer_id = $4
+        RETURNING id, customer_id, product_id, quantity, order_date",
+    )
+    .bind(payload.product_id)
+    .bind(payload.quantity)
+    .bind(id)
+    .bind(claims.sub)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(&state.pool, Some(claims.sub), "update", "order", id, &payload).await;
+
+    Ok(Json(order))
+}
+
+// DELETE `/orders/:id`: Remove an order (requires a valid access token
+// belonging to the order's own customer)
+pub async fn delete_order(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, Error> {
+    let result = sqlx::query("DELETE FROM \"order\" WHERE id = $1 AND customer_id = $2")
+        .bind(id)
+        .bind(claims.sub)
+        .execute(&*state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(Error::NotFound);
+    }
+
+    audit::record(
+        &state.pool,
+        Some(claims.sub),
+        "delete",
+        "order",
+        id,
+        &serde_json::json!({}),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// POST `/login`: Verify customer credentials and issue a signed access token
+pub async fn login(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginInput>,
+) -> Result<Json<LoginResponse>, Error> {
+    let customer = sqlx::query_as::<_, CustomerCredentials>(
+        "SELECT id, password FROM customer WHERE email = $1",
+    )
+    .bind(&payload.email)
+    .fetch_optional(&*state.pool)
+    .await?
+    .ok_or(Error::Unauthorized)?;
+
+    let password_matches = bcrypt::verify(&payload.password, &customer.password)
+        .map_err(|_| Error::Unauthorized)?;
+    if !password_matches {
+        return Err(Error::Unauthorized);
+    }
+
+    let now = Utc::now();
+    let claims = AccessClaims {
+        sub: customer.id,
+        iat: now.timestamp() as usize,
+        exp: (now + Duration::minutes(state.config.jwt_maxage)).timestamp() as usize,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+    )
+    .map_err(|_| Error::Unauthorized)?;
+
+    Ok(Json(LoginResponse { token }))
+}
+
+// GET `/audit`: Review the audit trail (requires a valid access token)
+pub async fn get_audit(
+    State(state): State<AppState>,
+    _claims: AccessClaims,
+    Query(params): Query<ListParams>,
+) -> Result<Json<Paginated<Audit>>, Error> {
+    let limit = normalize_limit(params.limit);
+    let offset = normalize_offset(params.offset);
+    // Default to newest-first, since that is how an audit trail is reviewed.
+    let sort = params.sort.unwrap_or_else(|| "-created_at".to_string());
+    let (column, descending) = resolve_sort(Some(&sort), &["created_at"], "created_at");
+    let direction = if descending { "DESC" } else { "ASC" };
+
+    let entries = sqlx::query_as::<_, Audit>(&format!(
+        "SELECT id, actor, action, target_resource, target_id, body, created_at
+        FROM audit ORDER BY {column} {direction} LIMIT $1 OFFSET $2"
+    ))
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&*state.pool)
+    .await?;
+
+    let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM audit")
+        .fetch_one(&*state.pool)
+        .await?;
+
+    Ok(Json(Paginated {
+        data: entries,
+        total,
+        limit,
+        offset,
+    }))
+}
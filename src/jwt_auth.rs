@@ -0,0 +1,49 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::Error;
+use crate::model::AppState;
+
+/// Claims embedded in the signed access token returned by `POST /login`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessClaims {
+    pub sub: Uuid,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+/// Extractor that requires a valid `Authorization: Bearer <token>` header,
+/// rejecting the request with `401 Unauthorized` if it is missing, malformed,
+/// or the token fails signature/expiry verification.
+#[async_trait]
+impl FromRequestParts<AppState> for AccessClaims {
+    type Rejection = Error;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(Error::Unauthorized)?;
+
+        let claims = decode::<AccessClaims>(
+            token,
+            &DecodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| Error::Unauthorized)?
+        .claims;
+
+        Ok(claims)
+    }
+}
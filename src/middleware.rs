@@ -0,0 +1,42 @@
+use axum::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+/// Builds the CORS layer from `CORS_ALLOWED_ORIGINS` / `CORS_ALLOWED_METHODS`
+/// / `CORS_ALLOWED_HEADERS` (comma-separated). Falls back to a permissive
+/// policy in debug builds when no origins are configured, and to a closed
+/// (default, deny-all) policy in release builds.
+pub fn cors_layer() -> CorsLayer {
+    let Ok(origins) = std::env::var("CORS_ALLOWED_ORIGINS") else {
+        return if cfg!(debug_assertions) {
+            CorsLayer::permissive()
+        } else {
+            CorsLayer::new()
+        };
+    };
+
+    let allowed_origins: Vec<HeaderValue> = origins
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .filter_map(|origin| origin.parse().ok())
+        .collect();
+
+    let allowed_methods: Vec<Method> = std::env::var("CORS_ALLOWED_METHODS")
+        .unwrap_or_else(|_| "GET,POST,PUT,PATCH,DELETE".to_string())
+        .split(',')
+        .map(str::trim)
+        .filter_map(|method| method.parse().ok())
+        .collect();
+
+    let allowed_headers: Vec<HeaderName> = std::env::var("CORS_ALLOWED_HEADERS")
+        .unwrap_or_else(|_| "authorization,content-type".to_string())
+        .split(',')
+        .map(str::trim)
+        .filter_map(|header| header.parse().ok())
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(allowed_origins)
+        .allow_methods(allowed_methods)
+        .allow_headers(allowed_headers)
+}
@@ -0,0 +1,132 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::config::Config;
+
+/// Shared application state handed to every extractor via `Router::with_state`.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: Arc<PgPool>,
+    pub config: Config,
+}
+
+// Struct representing the `Product` table
+#[derive(Debug, Serialize, FromRow)]
+pub struct Product {
+    pub id: Uuid,
+    pub name: String,
+    pub stock: i32,
+}
+
+// Struct representing the `Customer` table
+#[derive(Debug, Serialize, FromRow)]
+pub struct Customer {
+    pub id: Uuid,
+    pub name: String,
+}
+
+// Internal row used for login only; the password hash is never serialized
+// back to a client.
+#[derive(Debug, FromRow)]
+pub struct CustomerCredentials {
+    pub id: Uuid,
+    pub password: String,
+}
+
+// Struct representing the `Order` table
+#[derive(Debug, Serialize, FromRow)]
+pub struct Order {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: i32,
+    pub order_date: NaiveDateTime,
+}
+
+// Struct for new order input (used in POST `/orders`). Also serialized as
+// the audit-log body snapshot for order creation. The owning customer is
+// taken from the caller's access token, not from client input.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewOrder {
+    pub product_id: Uuid,
+    pub quantity: i32,
+}
+
+// Struct for updating a product's name (used in PUT `/products/:id`). Also
+// serialized as the audit-log body snapshot for product updates.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateProduct {
+    pub name: String,
+}
+
+// Struct for updating a customer's name (used in PUT `/customers/:id`). Also
+// serialized as the audit-log body snapshot for customer updates.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateCustomer {
+    pub name: String,
+}
+
+// Struct for updating an order (used in PUT `/orders/:id`). Also serialized
+// as the audit-log body snapshot for order updates. An order's owning
+// customer is fixed at creation and cannot be reassigned through this.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateOrder {
+    pub product_id: Uuid,
+    pub quantity: i32,
+}
+
+/// Generic page wrapper returned by every list endpoint.
+#[derive(Debug, Serialize)]
+pub struct Paginated<T> {
+    pub data: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+// Query params accepted by `GET /products` and `GET /customers`
+#[derive(Debug, Deserialize)]
+pub struct ListParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub sort: Option<String>,
+}
+
+// Query params accepted by `GET /orders`. Always implicitly scoped to the
+// caller's own customer, so there is no client-supplied `customer_id` filter.
+#[derive(Debug, Deserialize)]
+pub struct OrderListParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub sort: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+// Struct representing the `audit` table: one row per mutating request
+#[derive(Debug, Serialize, FromRow)]
+pub struct Audit {
+    pub id: Uuid,
+    pub actor: Option<Uuid>,
+    pub action: String,
+    pub target_resource: String,
+    pub target_id: Uuid,
+    pub body: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+// Struct for login input (used in POST `/login`)
+#[derive(Debug, Deserialize)]
+pub struct LoginInput {
+    pub email: String,
+    pub password: String,
+}
+
+// Struct for login output (used in POST `/login`)
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+}
@@ -0,0 +1,36 @@
+// Shared helpers for turning `ListParams`/`OrderListParams` into safe SQL.
+
+pub const DEFAULT_LIMIT: i64 = 20;
+pub const MAX_LIMIT: i64 = 100;
+
+/// Clamps a requested `limit` into `[1, MAX_LIMIT]`, defaulting to `DEFAULT_LIMIT`.
+pub fn normalize_limit(limit: Option<i64>) -> i64 {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+/// Clamps a requested `offset` to a non-negative value.
+pub fn normalize_offset(offset: Option<i64>) -> i64 {
+    offset.unwrap_or(0).max(0)
+}
+
+/// Resolves a `sort` query param (e.g. `name` or `-name`) against a whitelist
+/// of columns, returning `(column, descending)`. Falls back to
+/// `(default_column, false)` for an empty, missing, or unrecognized value —
+/// the column name is never taken from user input verbatim, since it is
+/// interpolated directly into the query string.
+pub fn resolve_sort(sort: Option<&str>, allowed: &[&str], default_column: &str) -> (String, bool) {
+    match sort.filter(|s| !s.is_empty()) {
+        Some(s) => {
+            let (column, descending) = match s.strip_prefix('-') {
+                Some(rest) => (rest, true),
+                None => (s, false),
+            };
+            if allowed.contains(&column) {
+                (column.to_string(), descending)
+            } else {
+                (default_column.to_string(), false)
+            }
+        }
+        None => (default_column.to_string(), false),
+    }
+}